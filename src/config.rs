@@ -0,0 +1,60 @@
+//! Named credential/domain profiles loaded from `~/.config/porkbun/config.toml`,
+//! selected via the global `--profile` flag. CLI args and env vars always win over
+//! values from this file.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub profiles: HashMap<String, Profile>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Profile {
+    pub api_key: Option<String>,
+    pub secret_key: Option<String>,
+    pub domain: Option<String>,
+}
+
+impl Config {
+    /// Looks up a named profile, falling back to one named `default` when `name` is `None`.
+    ///
+    /// An explicitly requested `name` that isn't configured is an error rather than a silent
+    /// `None`, so a typo'd `--profile` can't fall through to whatever credentials happen to be
+    /// in the environment.
+    pub fn profile(&self, name: Option<&str>) -> Result<Option<&Profile>> {
+        match name {
+            Some(name) => match self.profiles.get(name) {
+                Some(profile) => Ok(Some(profile)),
+                None => bail!("No profile named \"{name}\" in ~/.config/porkbun/config.toml"),
+            },
+            None => Ok(self.profiles.get("default")),
+        }
+    }
+}
+
+/// Loads the config file, returning an empty `Config` if it doesn't exist.
+pub fn load() -> Result<Config> {
+    let Some(path) = config_path() else {
+        return Ok(Config::default());
+    };
+
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+
+    let contents =
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn config_path() -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(PathBuf::from(home).join(".config/porkbun/config.toml"))
+}
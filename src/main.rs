@@ -1,25 +1,52 @@
+mod config;
+mod zonefile;
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write as _;
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 use anyhow::{Context, Result, bail};
 use clap::{Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 use tabled::{Table, Tabled};
 
+use zonefile::ZoneRecord;
+
 const BASE_URL: &str = "https://api.porkbun.com/api/json/v3";
 
 #[derive(Parser)]
 #[command(name = "porkbun", about = "CLI for managing Porkbun DNS records")]
 struct Cli {
-    /// Porkbun API key (or set PORKBUN_API_KEY)
+    /// Porkbun API key (or set PORKBUN_API_KEY, or configure a profile)
     #[arg(long, env = "PORKBUN_API_KEY")]
-    api_key: String,
+    api_key: Option<String>,
 
-    /// Porkbun secret API key (or set PORKBUN_SECRET_API_KEY)
+    /// Porkbun secret API key (or set PORKBUN_SECRET_API_KEY, or configure a profile)
     #[arg(long, env = "PORKBUN_SECRET_API_KEY")]
-    secret_key: String,
+    secret_key: Option<String>,
+
+    /// Named profile to load from ~/.config/porkbun/config.toml
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "table", global = true)]
+    output: OutputFormat,
 
     #[command(subcommand)]
     command: Command,
 }
 
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+}
+
 #[derive(Subcommand)]
 enum Command {
     /// List all domains
@@ -30,16 +57,148 @@ enum Command {
         #[command(subcommand)]
         action: DnsAction,
     },
+
+    /// Keep a DNS record pointed at this host's current public IP
+    Ddns {
+        /// Domain name
+        domain: String,
+        /// Subdomain (omit for root)
+        #[arg(short, long)]
+        name: Option<String>,
+        /// Record type to maintain
+        #[arg(short = 't', long = "record-type", value_enum, default_value = "A")]
+        record_type: DdnsRecordType,
+        /// Re-check every N seconds instead of running once
+        #[arg(long)]
+        interval: Option<u64>,
+        /// URL returning the caller's public IP as plain text
+        #[arg(long, default_value = "https://api.ipify.org")]
+        resolver_url: String,
+    },
+
+    /// Manage a domain's authoritative nameservers
+    Ns {
+        #[command(subcommand)]
+        action: NsAction,
+    },
+
+    /// Manage the DNSSEC DS records published to the registry
+    Dnssec {
+        #[command(subcommand)]
+        action: DnssecAction,
+    },
+
+    /// Retrieve the SSL certificate bundle Porkbun issued for a domain
+    Ssl {
+        #[command(subcommand)]
+        action: SslAction,
+    },
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum DdnsRecordType {
+    #[value(name = "A")]
+    A,
+    #[value(name = "AAAA")]
+    Aaaa,
+}
+
+impl DdnsRecordType {
+    fn as_str(self) -> &'static str {
+        match self {
+            DdnsRecordType::A => "A",
+            DdnsRecordType::Aaaa => "AAAA",
+        }
+    }
 }
 
 #[derive(Subcommand)]
-enum DnsAction {
-    /// List DNS records for a domain
+enum NsAction {
+    /// Print the current authoritative nameservers
+    Get {
+        /// Domain name
+        domain: String,
+    },
+
+    /// Replace the authoritative nameservers
+    Set {
+        /// Domain name
+        domain: String,
+        /// Nameservers to set (at least one)
+        #[arg(required = true)]
+        ns: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum DnssecAction {
+    /// List the DS records published for a domain
     List {
         /// Domain name
         domain: String,
     },
 
+    /// Create a DS record
+    Create {
+        /// Domain name
+        domain: String,
+        /// Key tag
+        #[arg(long = "key-tag")]
+        key_tag: String,
+        /// Algorithm number
+        #[arg(long = "alg")]
+        alg: String,
+        /// Digest type
+        #[arg(long = "digest-type")]
+        digest_type: String,
+        /// Digest value
+        #[arg(long)]
+        digest: String,
+        /// Maximum signature lifetime in seconds
+        #[arg(long = "max-sig-life")]
+        max_sig_life: Option<String>,
+    },
+
+    /// Delete a DS record by key tag
+    Delete {
+        /// Domain name
+        domain: String,
+        /// Key tag of the record to delete
+        #[arg(long = "key-tag")]
+        key_tag: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum SslAction {
+    /// Fetch the certificate chain, private key, and public key
+    Retrieve {
+        /// Domain name
+        domain: String,
+        /// Directory to write the PEM files to
+        #[arg(long, default_value = ".")]
+        out_dir: PathBuf,
+        /// Print a single field to stdout instead of writing files
+        #[arg(long, value_enum)]
+        stdout: Option<SslField>,
+    },
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum SslField {
+    Chain,
+    PrivateKey,
+    PublicKey,
+}
+
+#[derive(Subcommand)]
+enum DnsAction {
+    /// List DNS records for a domain
+    List {
+        /// Domain name (falls back to the profile's default domain)
+        domain: Option<String>,
+    },
+
     /// Create a DNS record
     Create {
         /// Domain name
@@ -101,11 +260,34 @@ enum DnsAction {
         #[arg(short, long)]
         name: Option<String>,
     },
+
+    /// Write the current records for a domain to a BIND-style zone file
+    Export {
+        /// Domain name
+        domain: String,
+        /// Zone file to write (defaults to `<domain>.zone`)
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+    },
+
+    /// Reconcile a domain's records against a BIND-style zone file
+    Import {
+        /// Domain name
+        domain: String,
+        /// Zone file to read
+        file: PathBuf,
+        /// Delete live records that are not present in the file
+        #[arg(long)]
+        prune: bool,
+        /// Print the planned create/edit/delete diff without changing any records
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 // --- API types ---
 
-#[derive(Serialize)]
+#[derive(Serialize, Clone)]
 struct Auth {
     apikey: String,
     secretapikey: String,
@@ -126,6 +308,20 @@ struct CreateEditRecord {
     prio: Option<String>,
 }
 
+#[derive(Serialize)]
+struct CreateDnssecRecord {
+    #[serde(flatten)]
+    auth: Auth,
+    #[serde(rename = "keyTag")]
+    key_tag: String,
+    alg: String,
+    #[serde(rename = "digestType")]
+    digest_type: String,
+    digest: String,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "maxSigLife")]
+    max_sig_life: Option<String>,
+}
+
 #[derive(Deserialize)]
 struct ApiResponse {
     status: String,
@@ -142,7 +338,7 @@ struct DomainListResponse {
     domains: Vec<DomainInfo>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 struct DomainInfo {
     domain: String,
     status: String,
@@ -173,7 +369,7 @@ struct DnsListResponse {
     records: Vec<DnsRecord>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, Clone)]
 struct DnsRecord {
     id: String,
     name: String,
@@ -214,6 +410,77 @@ struct CreateResponse {
     id: Option<serde_json::Value>,
 }
 
+#[derive(Deserialize, Serialize)]
+struct DnssecRecord {
+    #[serde(rename = "keyTag")]
+    key_tag: String,
+    alg: String,
+    #[serde(rename = "digestType")]
+    digest_type: String,
+    digest: String,
+    #[serde(default, rename = "maxSigLife")]
+    max_sig_life: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DnssecListResponse {
+    status: String,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    records: HashMap<String, DnssecRecord>,
+}
+
+#[derive(Tabled)]
+struct DnssecRow {
+    #[tabled(rename = "Key Tag")]
+    key_tag: String,
+    #[tabled(rename = "Algorithm")]
+    alg: String,
+    #[tabled(rename = "Digest Type")]
+    digest_type: String,
+    #[tabled(rename = "Digest")]
+    digest: String,
+    #[tabled(rename = "Max Sig Life")]
+    max_sig_life: String,
+}
+
+#[derive(Serialize)]
+struct UpdateNs {
+    #[serde(flatten)]
+    auth: Auth,
+    ns: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct NsListResponse {
+    status: String,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    ns: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct SslRetrieveResponse {
+    status: String,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    certificatechain: String,
+    #[serde(default)]
+    privatekey: String,
+    #[serde(default)]
+    publickey: String,
+}
+
+#[derive(Serialize)]
+struct ActionResult {
+    action: &'static str,
+    id: Option<String>,
+    status: &'static str,
+}
+
 fn check_status(status: &str, message: &Option<String>) -> Result<()> {
     if status != "SUCCESS" {
         bail!(
@@ -227,10 +494,27 @@ fn check_status(status: &str, message: &Option<String>) -> Result<()> {
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    let output = cli.output;
     let client = reqwest::Client::new();
+
+    let config = config::load()?;
+    let profile = config.profile(cli.profile.as_deref())?;
+
+    let api_key = cli
+        .api_key
+        .or_else(|| profile.and_then(|p| p.api_key.clone()))
+        .context("Missing API key: pass --api-key, set PORKBUN_API_KEY, or configure a profile")?;
+    let secret_key = cli
+        .secret_key
+        .or_else(|| profile.and_then(|p| p.secret_key.clone()))
+        .context(
+            "Missing secret API key: pass --secret-key, set PORKBUN_SECRET_API_KEY, or configure a profile",
+        )?;
+    let default_domain = profile.and_then(|p| p.domain.clone());
+
     let auth = Auth {
-        apikey: cli.api_key.clone(),
-        secretapikey: cli.secret_key.clone(),
+        apikey: api_key,
+        secretapikey: secret_key,
     };
 
     match cli.command {
@@ -246,27 +530,37 @@ async fn main() -> Result<()> {
 
             check_status(&resp.status, &resp.message)?;
 
-            if resp.domains.is_empty() {
-                println!("No domains found.");
-                return Ok(());
-            }
+            match output {
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&resp.domains)?),
+                OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&resp.domains)?),
+                OutputFormat::Table => {
+                    if resp.domains.is_empty() {
+                        println!("No domains found.");
+                        return Ok(());
+                    }
 
-            let rows: Vec<DomainRow> = resp
-                .domains
-                .into_iter()
-                .map(|d| DomainRow {
-                    domain: d.domain,
-                    status: d.status,
-                    created: d.create_date,
-                    expires: d.expire_date,
-                })
-                .collect();
-
-            println!("{}", Table::new(rows));
+                    let rows: Vec<DomainRow> = resp
+                        .domains
+                        .into_iter()
+                        .map(|d| DomainRow {
+                            domain: d.domain,
+                            status: d.status,
+                            created: d.create_date,
+                            expires: d.expire_date,
+                        })
+                        .collect();
+
+                    println!("{}", Table::new(rows));
+                }
+            }
         }
 
         Command::Dns { action } => match action {
             DnsAction::List { domain } => {
+                let domain = domain.or_else(|| default_domain.clone()).context(
+                    "Domain not specified: pass it or set a default domain in your profile",
+                )?;
+
                 let resp: DnsListResponse = client
                     .post(format!("{BASE_URL}/dns/retrieve/{domain}"))
                     .json(&auth)
@@ -278,13 +572,19 @@ async fn main() -> Result<()> {
 
                 check_status(&resp.status, &resp.message)?;
 
-                if resp.records.is_empty() {
-                    println!("No DNS records found for {domain}.");
-                    return Ok(());
-                }
+                match output {
+                    OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&resp.records)?),
+                    OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&resp.records)?),
+                    OutputFormat::Table => {
+                        if resp.records.is_empty() {
+                            println!("No DNS records found for {domain}.");
+                            return Ok(());
+                        }
 
-                let rows: Vec<DnsRow> = resp.records.into_iter().map(dns_row).collect();
-                println!("{}", Table::new(rows));
+                        let rows: Vec<DnsRow> = resp.records.into_iter().map(dns_row).collect();
+                        println!("{}", Table::new(rows));
+                    }
+                }
             }
 
             DnsAction::Create {
@@ -319,10 +619,20 @@ async fn main() -> Result<()> {
                     .id
                     .map(|v| v.to_string())
                     .unwrap_or_else(|| "unknown".into());
-                let display_name = name.as_deref().unwrap_or("(root)");
-                println!(
-                    "Created {record_type} record for {display_name}.{domain} -> {content} (id: {id_str})"
-                );
+
+                if let OutputFormat::Json = output {
+                    let result = ActionResult {
+                        action: "create",
+                        id: Some(id_str),
+                        status: "SUCCESS",
+                    };
+                    println!("{}", serde_json::to_string(&result)?);
+                } else {
+                    let display_name = name.as_deref().unwrap_or("(root)");
+                    println!(
+                        "Created {record_type} record for {display_name}.{domain} -> {content} (id: {id_str})"
+                    );
+                }
             }
 
             DnsAction::Edit {
@@ -353,7 +663,17 @@ async fn main() -> Result<()> {
                     .await?;
 
                 check_status(&resp.status, &resp.message)?;
-                println!("Updated record {id} on {domain}.");
+
+                if let OutputFormat::Json = output {
+                    let result = ActionResult {
+                        action: "edit",
+                        id: Some(id),
+                        status: "SUCCESS",
+                    };
+                    println!("{}", serde_json::to_string(&result)?);
+                } else {
+                    println!("Updated record {id} on {domain}.");
+                }
             }
 
             DnsAction::Delete { domain, id } => {
@@ -367,7 +687,17 @@ async fn main() -> Result<()> {
                     .await?;
 
                 check_status(&resp.status, &resp.message)?;
-                println!("Deleted record {id} from {domain}.");
+
+                if let OutputFormat::Json = output {
+                    let result = ActionResult {
+                        action: "delete",
+                        id: Some(id),
+                        status: "SUCCESS",
+                    };
+                    println!("{}", serde_json::to_string(&result)?);
+                } else {
+                    println!("Deleted record {id} from {domain}.");
+                }
             }
 
             DnsAction::DeleteByNameType {
@@ -392,12 +722,384 @@ async fn main() -> Result<()> {
                     .await?;
 
                 check_status(&resp.status, &resp.message)?;
-                let display = if subdomain.is_empty() {
-                    format!("root {record_type}")
+
+                if let OutputFormat::Json = output {
+                    let result = ActionResult {
+                        action: "delete",
+                        id: None,
+                        status: "SUCCESS",
+                    };
+                    println!("{}", serde_json::to_string(&result)?);
                 } else {
-                    format!("{subdomain} {record_type}")
+                    let display = if subdomain.is_empty() {
+                        format!("root {record_type}")
+                    } else {
+                        format!("{subdomain} {record_type}")
+                    };
+                    println!("Deleted {display} records from {domain}.");
+                }
+            }
+
+            DnsAction::Export { domain, file } => {
+                let resp: DnsListResponse = client
+                    .post(format!("{BASE_URL}/dns/retrieve/{domain}"))
+                    .json(&auth)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                let records: Vec<ZoneRecord> = resp
+                    .records
+                    .into_iter()
+                    .map(|r| dns_record_to_zone(&r, &domain))
+                    .collect();
+
+                let path = file.unwrap_or_else(|| PathBuf::from(format!("{domain}.zone")));
+                fs::write(&path, zonefile::serialize(&domain, &records))
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+
+                println!("Exported {} record(s) to {}", records.len(), path.display());
+            }
+
+            DnsAction::Import {
+                domain,
+                file,
+                prune,
+                dry_run,
+            } => {
+                let contents = fs::read_to_string(&file)
+                    .with_context(|| format!("Failed to read {}", file.display()))?;
+                let wanted = zonefile::parse(&contents)?;
+
+                let resp: DnsListResponse = client
+                    .post(format!("{BASE_URL}/dns/retrieve/{domain}"))
+                    .json(&auth)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                let plan = plan_import(&domain, resp.records, wanted, prune);
+
+                if dry_run {
+                    print_import_plan(&plan);
+                    return Ok(());
+                }
+
+                for zr in &plan.creates {
+                    let body = CreateEditRecord {
+                        auth: auth.clone(),
+                        name: zone_name_to_subdomain(&zr.name),
+                        record_type: zr.record_type.clone(),
+                        content: zr.content.clone(),
+                        ttl: Some(zr.ttl.clone()),
+                        prio: zr.prio.clone(),
+                    };
+
+                    let resp: CreateResponse = client
+                        .post(format!("{BASE_URL}/dns/create/{domain}"))
+                        .json(&body)
+                        .send()
+                        .await
+                        .context("Failed to contact Porkbun API")?
+                        .json()
+                        .await?;
+
+                    check_status(&resp.status, &resp.message)?;
+                    println!("Created {} {} -> {}", zr.name_or_root(), zr.record_type, zr.content);
+                }
+
+                for (id, zr) in &plan.edits {
+                    let body = CreateEditRecord {
+                        auth: auth.clone(),
+                        name: zone_name_to_subdomain(&zr.name),
+                        record_type: zr.record_type.clone(),
+                        content: zr.content.clone(),
+                        ttl: Some(zr.ttl.clone()),
+                        prio: zr.prio.clone(),
+                    };
+
+                    let resp: ApiResponse = client
+                        .post(format!("{BASE_URL}/dns/edit/{domain}/{id}"))
+                        .json(&body)
+                        .send()
+                        .await
+                        .context("Failed to contact Porkbun API")?
+                        .json()
+                        .await?;
+
+                    check_status(&resp.status, &resp.message)?;
+                    println!("Updated {} {} -> {}", zr.name_or_root(), zr.record_type, zr.content);
+                }
+
+                for record in &plan.deletes {
+                    let resp: ApiResponse = client
+                        .post(format!("{BASE_URL}/dns/delete/{domain}/{}", record.id))
+                        .json(&auth)
+                        .send()
+                        .await
+                        .context("Failed to contact Porkbun API")?
+                        .json()
+                        .await?;
+
+                    check_status(&resp.status, &resp.message)?;
+                    println!("Deleted {} {}", record.name, record.record_type);
+                }
+
+                println!(
+                    "Import complete: {} created, {} edited, {} deleted.",
+                    plan.creates.len(),
+                    plan.edits.len(),
+                    plan.deletes.len()
+                );
+            }
+        },
+
+        Command::Ddns {
+            domain,
+            name,
+            record_type,
+            interval,
+            resolver_url,
+        } => loop {
+            let result = update_ddns_record(
+                &client,
+                &auth,
+                &domain,
+                &name,
+                record_type.as_str(),
+                &resolver_url,
+            )
+            .await;
+
+            match (result, interval) {
+                (Ok(()), _) => {}
+                // One-shot mode: let the caller see the failure directly.
+                (Err(err), None) => return Err(err),
+                // Daemon mode: log and keep polling rather than taking the process down.
+                (Err(err), Some(_)) => eprintln!("ddns: {err:#}"),
+            }
+
+            match interval {
+                Some(seconds) => tokio::time::sleep(Duration::from_secs(seconds)).await,
+                None => break,
+            }
+        },
+
+        Command::Ns { action } => match action {
+            NsAction::Get { domain } => {
+                let resp: NsListResponse = client
+                    .post(format!("{BASE_URL}/domain/getNs/{domain}"))
+                    .json(&auth)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                match output {
+                    OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&resp.ns)?),
+                    OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&resp.ns)?),
+                    OutputFormat::Table => {
+                        for ns in &resp.ns {
+                            println!("{ns}");
+                        }
+                    }
+                }
+            }
+
+            NsAction::Set { domain, ns } => {
+                let body = UpdateNs {
+                    auth,
+                    ns: ns.clone(),
+                };
+
+                let resp: ApiResponse = client
+                    .post(format!("{BASE_URL}/domain/updateNs/{domain}"))
+                    .json(&body)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                if let OutputFormat::Json = output {
+                    let result = ActionResult {
+                        action: "update_ns",
+                        id: None,
+                        status: "SUCCESS",
+                    };
+                    println!("{}", serde_json::to_string(&result)?);
+                } else {
+                    println!("Updated nameservers for {domain}: {}", ns.join(", "));
+                }
+            }
+        },
+
+        Command::Dnssec { action } => match action {
+            DnssecAction::List { domain } => {
+                let resp: DnssecListResponse = client
+                    .post(format!("{BASE_URL}/dns/getDnssecRecords/{domain}"))
+                    .json(&auth)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                match output {
+                    OutputFormat::Json => {
+                        println!("{}", serde_json::to_string_pretty(&resp.records)?)
+                    }
+                    OutputFormat::Yaml => print!("{}", serde_yaml::to_string(&resp.records)?),
+                    OutputFormat::Table => {
+                        if resp.records.is_empty() {
+                            println!("No DNSSEC records found for {domain}.");
+                            return Ok(());
+                        }
+
+                        let rows: Vec<DnssecRow> = resp
+                            .records
+                            .into_values()
+                            .map(|r| DnssecRow {
+                                key_tag: r.key_tag,
+                                alg: r.alg,
+                                digest_type: r.digest_type,
+                                digest: r.digest,
+                                max_sig_life: r.max_sig_life.unwrap_or_default(),
+                            })
+                            .collect();
+
+                        println!("{}", Table::new(rows));
+                    }
+                }
+            }
+
+            DnssecAction::Create {
+                domain,
+                key_tag,
+                alg,
+                digest_type,
+                digest,
+                max_sig_life,
+            } => {
+                let body = CreateDnssecRecord {
+                    auth,
+                    key_tag: key_tag.clone(),
+                    alg,
+                    digest_type,
+                    digest,
+                    max_sig_life,
                 };
-                println!("Deleted {display} records from {domain}.");
+
+                let resp: ApiResponse = client
+                    .post(format!("{BASE_URL}/dns/createDnssecRecord/{domain}"))
+                    .json(&body)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                if let OutputFormat::Json = output {
+                    let result = ActionResult {
+                        action: "create_dnssec",
+                        id: Some(key_tag),
+                        status: "SUCCESS",
+                    };
+                    println!("{}", serde_json::to_string(&result)?);
+                } else {
+                    println!("Created DS record (key tag {key_tag}) for {domain}.");
+                }
+            }
+
+            DnssecAction::Delete { domain, key_tag } => {
+                let resp: ApiResponse = client
+                    .post(format!("{BASE_URL}/dns/deleteDnssecRecord/{domain}/{key_tag}"))
+                    .json(&auth)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                if let OutputFormat::Json = output {
+                    let result = ActionResult {
+                        action: "delete_dnssec",
+                        id: Some(key_tag),
+                        status: "SUCCESS",
+                    };
+                    println!("{}", serde_json::to_string(&result)?);
+                } else {
+                    println!("Deleted DS record (key tag {key_tag}) from {domain}.");
+                }
+            }
+        },
+
+        Command::Ssl { action } => match action {
+            SslAction::Retrieve {
+                domain,
+                out_dir,
+                stdout,
+            } => {
+                let resp: SslRetrieveResponse = client
+                    .post(format!("{BASE_URL}/ssl/retrieve/{domain}"))
+                    .json(&auth)
+                    .send()
+                    .await
+                    .context("Failed to contact Porkbun API")?
+                    .json()
+                    .await?;
+
+                check_status(&resp.status, &resp.message)?;
+
+                if let Some(field) = stdout {
+                    let value = match field {
+                        SslField::Chain => &resp.certificatechain,
+                        SslField::PrivateKey => &resp.privatekey,
+                        SslField::PublicKey => &resp.publickey,
+                    };
+                    print!("{value}");
+                    return Ok(());
+                }
+
+                fs::create_dir_all(&out_dir)
+                    .with_context(|| format!("Failed to create {}", out_dir.display()))?;
+
+                let files = [
+                    ("certificatechain.pem", &resp.certificatechain),
+                    ("privatekey.pem", &resp.privatekey),
+                    ("publickey.pem", &resp.publickey),
+                ];
+
+                for (filename, contents) in files {
+                    let path = out_dir.join(filename);
+                    if filename == "privatekey.pem" {
+                        write_private_key(&path, contents)?;
+                    } else {
+                        fs::write(&path, contents)
+                            .with_context(|| format!("Failed to write {}", path.display()))?;
+                    }
+                }
+
+                println!("Wrote SSL bundle for {domain} to {}", out_dir.display());
             }
         },
     }
@@ -405,6 +1107,115 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+async fn update_ddns_record(
+    client: &reqwest::Client,
+    auth: &Auth,
+    domain: &str,
+    name: &Option<String>,
+    record_type: &str,
+    resolver_url: &str,
+) -> Result<()> {
+    let public_ip = client
+        .get(resolver_url)
+        .send()
+        .await
+        .context("Failed to contact IP resolver")?
+        .text()
+        .await?
+        .trim()
+        .to_string();
+
+    let fqdn = match name {
+        Some(n) => format!("{n}.{domain}"),
+        None => domain.to_string(),
+    };
+
+    let resp: DnsListResponse = client
+        .post(format!("{BASE_URL}/dns/retrieve/{domain}"))
+        .json(auth)
+        .send()
+        .await
+        .context("Failed to contact Porkbun API")?
+        .json()
+        .await?;
+
+    check_status(&resp.status, &resp.message)?;
+
+    let existing = resp
+        .records
+        .into_iter()
+        .find(|r| r.name == fqdn && r.record_type == record_type);
+
+    match existing {
+        Some(record) if record.content == public_ip => {
+            println!("{fqdn} {record_type} already points at {public_ip}, unchanged.");
+        }
+        Some(record) => {
+            let body = CreateEditRecord {
+                auth: auth.clone(),
+                name: name.clone(),
+                record_type: record_type.to_string(),
+                content: public_ip.clone(),
+                ttl: None,
+                prio: None,
+            };
+
+            let resp: ApiResponse = client
+                .post(format!("{BASE_URL}/dns/edit/{domain}/{}", record.id))
+                .json(&body)
+                .send()
+                .await
+                .context("Failed to contact Porkbun API")?
+                .json()
+                .await?;
+
+            check_status(&resp.status, &resp.message)?;
+            println!("Updated {fqdn} {record_type} -> {public_ip}");
+        }
+        None => {
+            let body = CreateEditRecord {
+                auth: auth.clone(),
+                name: name.clone(),
+                record_type: record_type.to_string(),
+                content: public_ip.clone(),
+                ttl: None,
+                prio: None,
+            };
+
+            let resp: CreateResponse = client
+                .post(format!("{BASE_URL}/dns/create/{domain}"))
+                .json(&body)
+                .send()
+                .await
+                .context("Failed to contact Porkbun API")?
+                .json()
+                .await?;
+
+            check_status(&resp.status, &resp.message)?;
+            println!("Created {fqdn} {record_type} -> {public_ip}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a private key with `0o600` permissions instead of the process umask default.
+fn write_private_key(path: &Path, contents: &str) -> Result<()> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    // `mode` only applies when the file is created; tighten an existing file too.
+    file.set_permissions(fs::Permissions::from_mode(0o600))
+        .with_context(|| format!("Failed to set permissions on {}", path.display()))?;
+
+    file.write_all(contents.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
 fn dns_row(r: DnsRecord) -> DnsRow {
     DnsRow {
         id: r.id,
@@ -416,3 +1227,183 @@ fn dns_row(r: DnsRecord) -> DnsRow {
         notes: r.notes.unwrap_or_default(),
     }
 }
+
+/// Converts a live API record's fully-qualified name into a zone-file-relative one.
+fn dns_record_to_zone(r: &DnsRecord, domain: &str) -> ZoneRecord {
+    let name = r
+        .name
+        .strip_suffix(&format!(".{domain}"))
+        .unwrap_or("")
+        .to_string();
+
+    ZoneRecord {
+        name,
+        ttl: r.ttl.clone(),
+        record_type: r.record_type.clone(),
+        prio: r.prio.clone(),
+        content: r.content.clone(),
+    }
+}
+
+fn zone_name_to_subdomain(name: &str) -> Option<String> {
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+struct ImportPlan {
+    creates: Vec<ZoneRecord>,
+    edits: Vec<(String, ZoneRecord)>,
+    deletes: Vec<DnsRecord>,
+}
+
+/// Diffs the records wanted by a zone file against what's live, matching by name+type.
+fn plan_import(
+    domain: &str,
+    live: Vec<DnsRecord>,
+    wanted: Vec<ZoneRecord>,
+    prune: bool,
+) -> ImportPlan {
+    let mut remaining = live;
+    let mut creates = Vec::new();
+    let mut edits = Vec::new();
+
+    for zr in wanted {
+        let full_name = if zr.name.is_empty() {
+            domain.to_string()
+        } else {
+            format!("{}.{domain}", zr.name)
+        };
+
+        let matched = remaining
+            .iter()
+            .position(|r| r.name == full_name && r.record_type == zr.record_type);
+
+        match matched {
+            Some(pos) => {
+                let live_record = remaining.remove(pos);
+                let changed = live_record.content != zr.content
+                    || live_record.ttl != zr.ttl
+                    || live_record.prio != zr.prio;
+                if changed {
+                    edits.push((live_record.id, zr));
+                }
+            }
+            None => creates.push(zr),
+        }
+    }
+
+    let deletes = if prune { remaining } else { Vec::new() };
+
+    ImportPlan {
+        creates,
+        edits,
+        deletes,
+    }
+}
+
+fn print_import_plan(plan: &ImportPlan) {
+    for zr in &plan.creates {
+        println!("+ create {} {} -> {}", zr.name_or_root(), zr.record_type, zr.content);
+    }
+    for (id, zr) in &plan.edits {
+        println!(
+            "~ edit   {} {} -> {} (id: {id})",
+            zr.name_or_root(),
+            zr.record_type,
+            zr.content
+        );
+    }
+    for record in &plan.deletes {
+        println!("- delete {} {} (id: {})", record.name, record.record_type, record.id);
+    }
+
+    if plan.creates.is_empty() && plan.edits.is_empty() && plan.deletes.is_empty() {
+        println!("No changes.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn live_record(id: &str, name: &str, content: &str) -> DnsRecord {
+        DnsRecord {
+            id: id.to_string(),
+            name: name.to_string(),
+            record_type: "A".to_string(),
+            content: content.to_string(),
+            ttl: "600".to_string(),
+            prio: None,
+            notes: None,
+        }
+    }
+
+    fn zone_record(name: &str, content: &str) -> ZoneRecord {
+        ZoneRecord {
+            name: name.to_string(),
+            ttl: "600".to_string(),
+            record_type: "A".to_string(),
+            prio: None,
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn plan_import_creates_missing_records() {
+        let plan = plan_import(
+            "example.com",
+            vec![],
+            vec![zone_record("www", "1.2.3.4")],
+            false,
+        );
+
+        assert_eq!(plan.creates, vec![zone_record("www", "1.2.3.4")]);
+        assert!(plan.edits.is_empty());
+        assert!(plan.deletes.is_empty());
+    }
+
+    #[test]
+    fn plan_import_edits_changed_records_matched_by_name_and_type() {
+        let live = vec![live_record("1", "www.example.com", "1.2.3.4")];
+        let plan = plan_import(
+            "example.com",
+            live,
+            vec![zone_record("www", "5.6.7.8")],
+            false,
+        );
+
+        assert!(plan.creates.is_empty());
+        assert_eq!(plan.edits, vec![("1".to_string(), zone_record("www", "5.6.7.8"))]);
+        assert!(plan.deletes.is_empty());
+    }
+
+    #[test]
+    fn plan_import_leaves_unchanged_records_alone() {
+        let live = vec![live_record("1", "www.example.com", "1.2.3.4")];
+        let plan = plan_import(
+            "example.com",
+            live,
+            vec![zone_record("www", "1.2.3.4")],
+            false,
+        );
+
+        assert!(plan.creates.is_empty());
+        assert!(plan.edits.is_empty());
+        assert!(plan.deletes.is_empty());
+    }
+
+    #[test]
+    fn plan_import_only_deletes_unmatched_records_when_pruning() {
+        let live = vec![live_record("1", "old.example.com", "1.2.3.4")];
+
+        let plan = plan_import("example.com", live.clone(), vec![], false);
+        assert!(plan.deletes.is_empty());
+
+        let plan = plan_import("example.com", live, vec![], true);
+        assert_eq!(plan.deletes.len(), 1);
+        assert_eq!(plan.deletes[0].id, "1");
+    }
+}
@@ -0,0 +1,227 @@
+//! Reading and writing DNS records in standard BIND master-file syntax
+//! (`name TTL CLASS TYPE [prio] rdata`), used by `dns export`/`dns import`.
+
+use anyhow::{Result, bail};
+
+/// A single resource record as read from or written to a zone file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneRecord {
+    /// Subdomain relative to the zone's origin, or empty for the root.
+    pub name: String,
+    pub ttl: String,
+    pub record_type: String,
+    pub prio: Option<String>,
+    pub content: String,
+}
+
+/// Serializes records into BIND master-file syntax, tagged with a `$ORIGIN` directive.
+pub fn serialize(origin: &str, records: &[ZoneRecord]) -> String {
+    let mut out = format!("$ORIGIN {origin}.\n");
+
+    for r in records {
+        let name = if r.name.is_empty() { "@" } else { &r.name };
+        let content = quote_if_needed(&r.content);
+        match &r.prio {
+            Some(prio) => {
+                out.push_str(&format!(
+                    "{name}\t{}\tIN\t{}\t{prio}\t{content}\n",
+                    r.ttl, r.record_type
+                ));
+            }
+            None => {
+                out.push_str(&format!("{name}\t{}\tIN\t{}\t{content}\n", r.ttl, r.record_type));
+            }
+        }
+    }
+
+    out
+}
+
+/// Quotes rdata that contains whitespace, `;`, or `"` so it survives comment-stripping and
+/// whitespace-splitting on re-parse (e.g. a TXT record's DMARC policy string).
+fn quote_if_needed(content: &str) -> String {
+    let needs_quoting =
+        content.is_empty() || content.contains(char::is_whitespace) || content.contains([';', '"']);
+
+    if needs_quoting {
+        format!("\"{}\"", content.replace('"', "\\\""))
+    } else {
+        content.to_string()
+    }
+}
+
+/// Strips `"`-quoted content of its surrounding quotes, unescaping `\"`.
+fn unquote(content: &str) -> String {
+    match content.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+        Some(inner) => inner.replace("\\\"", "\""),
+        None => content.to_string(),
+    }
+}
+
+/// Finds the start of a `;` comment, ignoring semicolons inside a `"`-quoted string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_quotes = false;
+    let mut prev = '\0';
+
+    for (i, c) in line.char_indices() {
+        match c {
+            // An escaped `\"` inside quoted content neither opens nor closes the string.
+            '"' if prev != '\\' => in_quotes = !in_quotes,
+            ';' if !in_quotes => return &line[..i],
+            _ => {}
+        }
+        prev = c;
+    }
+
+    line
+}
+
+impl ZoneRecord {
+    /// Name for display purposes, using `@` for the zone root.
+    pub fn name_or_root(&self) -> &str {
+        if self.name.is_empty() { "@" } else { &self.name }
+    }
+}
+
+/// Parses BIND master-file syntax, skipping blank lines, `;` comments, and `$` directives.
+pub fn parse(input: &str) -> Result<Vec<ZoneRecord>> {
+    let mut records = Vec::new();
+
+    for (lineno, raw_line) in input.lines().enumerate() {
+        let line = strip_comment(raw_line).trim();
+        if line.is_empty() || line.starts_with('$') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 4 {
+            bail!(
+                "zone file line {}: expected `name TTL CLASS TYPE [prio] rdata`, got `{raw_line}`",
+                lineno + 1
+            );
+        }
+
+        let name = if fields[0] == "@" {
+            String::new()
+        } else {
+            fields[0].to_string()
+        };
+        let ttl = fields[1].to_string();
+        // fields[2] is the CLASS (always IN in practice); Porkbun has no use for it.
+        let record_type = fields[3].to_string();
+
+        let (prio, rdata_field) = if matches!(record_type.as_str(), "MX" | "SRV") {
+            if fields.len() < 6 {
+                bail!(
+                    "zone file line {}: {record_type} record is missing a priority",
+                    lineno + 1
+                );
+            }
+            (Some(fields[4].to_string()), 5)
+        } else {
+            if fields.len() < 5 {
+                bail!(
+                    "zone file line {}: record is missing its rdata, got `{raw_line}`",
+                    lineno + 1
+                );
+            }
+            (None, 4)
+        };
+        // Slice the rdata out of the line rather than re-joining `fields`, so whitespace
+        // inside quoted content is kept exactly.
+        let mut raw_content = line;
+        for _ in 0..rdata_field {
+            raw_content = raw_content.trim_start();
+            raw_content = &raw_content[raw_content.find(char::is_whitespace).unwrap_or(raw_content.len())..];
+        }
+        let content = unquote(raw_content.trim_start());
+
+        records.push(ZoneRecord {
+            name,
+            ttl,
+            record_type,
+            prio,
+            content,
+        });
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn a_record(name: &str) -> ZoneRecord {
+        ZoneRecord {
+            name: name.to_string(),
+            ttl: "600".to_string(),
+            record_type: "A".to_string(),
+            prio: None,
+            content: "1.2.3.4".to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_serialize_and_parse() {
+        let records = vec![
+            a_record(""),
+            a_record("www"),
+            ZoneRecord {
+                name: "".to_string(),
+                ttl: "300".to_string(),
+                record_type: "MX".to_string(),
+                prio: Some("10".to_string()),
+                content: "mail.example.com".to_string(),
+            },
+        ];
+
+        let zone = serialize("example.com", &records);
+        let parsed = parse(&zone).unwrap();
+
+        assert_eq!(parsed, records);
+    }
+
+    #[test]
+    fn round_trips_txt_content_with_semicolons() {
+        let records = vec![ZoneRecord {
+            name: "_dmarc".to_string(),
+            ttl: "600".to_string(),
+            record_type: "TXT".to_string(),
+            prio: None,
+            content: "v=DMARC1; p=reject; rua=mailto:x@example.com".to_string(),
+        }];
+
+        let zone = serialize("example.com", &records);
+        let parsed = parse(&zone).unwrap();
+
+        assert_eq!(parsed, records);
+    }
+
+    #[test]
+    fn parse_keeps_quoted_semicolons_but_still_strips_trailing_comments() {
+        let records =
+            parse("_dmarc\t600\tIN\tTXT\t\"v=DMARC1; p=reject\" ; trailing comment\n").unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].content, "v=DMARC1; p=reject");
+    }
+
+    #[test]
+    fn parse_rejects_line_missing_rdata() {
+        let err = parse("@\t600\tIN\tA\n").unwrap_err();
+        assert!(err.to_string().contains("missing its rdata"));
+    }
+
+    #[test]
+    fn parse_rejects_mx_record_missing_priority() {
+        let err = parse("@\t600\tIN\tMX\tmail.example.com\n").unwrap_err();
+        assert!(err.to_string().contains("missing a priority"));
+    }
+
+    #[test]
+    fn parse_ignores_comments_and_directives() {
+        let records = parse("$ORIGIN example.com.\n; a comment\n\nwww\t600\tIN\tA\t1.2.3.4\n").unwrap();
+        assert_eq!(records, vec![a_record("www")]);
+    }
+}